@@ -0,0 +1,569 @@
+//! Dynamic fee engine for attestation submission.
+//!
+//! Fees are derived from a per-token base fee adjusted by a business's tier
+//! discount and a volume discount bracket. The admin registers the set of
+//! accepted fee tokens; a business selects which registered token to settle
+//! in when it submits an attestation. Fee storage and discount lookups all
+//! live here so the contract surface in `lib.rs` stays thin.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+const BPS_DENOM: i128 = 10_000;
+
+/// Instance-storage keys owned by the fee engine.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum DataKey {
+    /// Contract admin; sole updater of fee configuration.
+    Admin,
+    /// Default (legacy) single-token fee configuration.
+    FeeConfig,
+    /// Discount in basis points for a tier level.
+    TierDiscount(u32),
+    /// Tier assignment for a business.
+    BusinessTier(Address),
+    /// Ascending volume thresholds for bracket discounts.
+    VolumeThresholds,
+    /// Per-threshold discount values (basis points).
+    VolumeDiscounts,
+    /// Cumulative attestation count for a business.
+    BusinessCount(Address),
+    /// Registry of accepted fee-token addresses.
+    FeeTokens,
+    /// Per-token fee configuration.
+    FeeToken(Address),
+    /// Per-(business, period) fee ledger entry.
+    FeeLedger(Address, String),
+    /// Net fees collected for a period (charges minus refunds).
+    PeriodTotal(String),
+    /// Refund policy (window and partial share) for supersessions.
+    RefundPolicy,
+    /// Per-business collector override.
+    BusinessCollector(Address),
+    /// Split-collector addresses.
+    SplitAddrs,
+    /// Split-collector basis-point shares (aligned with `SplitAddrs`).
+    SplitBps,
+    /// Stored attestation for (business, period).
+    Attestation(Address, String),
+}
+
+/// Core fee schedule for the default settlement token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeConfig {
+    /// Token contract address for fee payment.
+    pub token: Address,
+    /// Address that receives collected fees.
+    pub collector: Address,
+    /// Base fee in the token's smallest units.
+    pub base_fee: i128,
+    /// Fee charged per 1 KiB of attestation payload (ledger-write proxy).
+    pub fee_per_1kb: i64,
+    /// Fee charged per Merkle leaf committed.
+    pub fee_per_entry: i64,
+    /// Master switch for fee collection.
+    pub enabled: bool,
+}
+
+/// Per-token fee entry for the multi-token acceptance set.
+///
+/// `multiplier_bps` scales `base_fee` so a single logical price can be
+/// expressed across tokens with differing decimals or value.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeToken {
+    /// Token contract address accepted for payment.
+    pub token: Address,
+    /// Base fee in this token's smallest units.
+    pub base_fee: i128,
+    /// Scaling factor in basis points applied to `base_fee`.
+    pub multiplier_bps: u32,
+}
+
+/// Per-(business, period) record of what was charged and refunded.
+///
+/// `charged` is the original fee; `refunded` accumulates any refunds (and can
+/// never exceed `charged`). `recipients`/`amounts` capture exactly who
+/// received which share of the charge — the split recipients when a split is
+/// active, otherwise the single effective collector — so a supersession refund
+/// can reverse each transfer proportionally rather than debiting one party for
+/// money it never received.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeLedgerEntry {
+    pub charged: i128,
+    pub refunded: i128,
+    pub recipients: Vec<Address>,
+    pub amounts: Vec<i128>,
+    pub token: Address,
+    pub payer: Address,
+    pub charged_at: u64,
+}
+
+/// Refund terms applied when an attestation is superseded.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefundPolicy {
+    /// Seconds after the charge during which a refund may be issued.
+    pub window_secs: u64,
+    /// Share of the original fee refundable, in basis points.
+    pub refund_bps: u32,
+}
+
+// ── Admin / init ────────────────────────────────────────────────────
+
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("not initialized")
+}
+
+pub fn require_admin(env: &Env) {
+    get_admin(env).require_auth();
+}
+
+// ── Fee configuration ───────────────────────────────────────────────
+
+pub fn set_fee_config(env: &Env, config: &FeeConfig) {
+    env.storage().instance().set(&DataKey::FeeConfig, config);
+}
+
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&DataKey::FeeConfig)
+}
+
+/// Register or update a per-token fee entry and add it to the accepted set.
+pub fn add_fee_token(env: &Env, entry: &FeeToken) {
+    let mut tokens = get_fee_tokens(env);
+    if !tokens.iter().any(|t| t == entry.token) {
+        tokens.push_back(entry.token.clone());
+        env.storage().instance().set(&DataKey::FeeTokens, &tokens);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeToken(entry.token.clone()), entry);
+}
+
+/// Remove a token from the accepted set and drop its configuration.
+pub fn remove_fee_token(env: &Env, token: &Address) {
+    let tokens = get_fee_tokens(env);
+    let mut remaining = Vec::new(env);
+    for t in tokens.iter() {
+        if t != *token {
+            remaining.push_back(t);
+        }
+    }
+    env.storage().instance().set(&DataKey::FeeTokens, &remaining);
+    env.storage()
+        .instance()
+        .remove(&DataKey::FeeToken(token.clone()));
+}
+
+/// Return the list of accepted fee-token addresses.
+pub fn get_fee_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeTokens)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_fee_token(env: &Env, token: &Address) -> Option<FeeToken> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeToken(token.clone()))
+}
+
+// ── Fee-recipient routing ───────────────────────────────────────────
+
+pub fn set_business_collector(env: &Env, business: &Address, collector: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::BusinessCollector(business.clone()), collector);
+}
+
+fn get_business_collector(env: &Env, business: &Address) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::BusinessCollector(business.clone()))
+}
+
+pub fn set_fee_split(env: &Env, addrs: &Vec<Address>, bps: &Vec<u32>) {
+    assert_eq!(
+        addrs.len(),
+        bps.len(),
+        "split addresses and shares must be equal length"
+    );
+    assert!(!addrs.is_empty(), "split must have at least one recipient");
+    let total: u32 = bps.iter().sum();
+    assert_eq!(total, 10_000, "split shares must sum to 10000");
+    env.storage().instance().set(&DataKey::SplitAddrs, addrs);
+    env.storage().instance().set(&DataKey::SplitBps, bps);
+}
+
+/// Distribute `fee` from `payer` to the resolved recipient(s), returning the
+/// exact (recipient, amount) pairs paid so the charge can be reversed later.
+///
+/// A per-business override always takes precedence and receives the whole
+/// fee. Otherwise, when a split spec is configured the fee is apportioned by
+/// basis points (the last recipient absorbs any rounding remainder); with no
+/// override or split, the global collector receives the whole fee.
+fn distribute_fee(
+    env: &Env,
+    business: &Address,
+    payer: &Address,
+    token: &Address,
+    fee: i128,
+) -> (Vec<Address>, Vec<i128>) {
+    let token_client = soroban_sdk::token::Client::new(env, token);
+    let config = get_fee_config(env).expect("fees not configured");
+    let mut recipients = Vec::new(env);
+    let mut amounts = Vec::new(env);
+
+    if let Some(override_collector) = get_business_collector(env, business) {
+        token_client.transfer(payer, &override_collector, &fee);
+        recipients.push_back(override_collector);
+        amounts.push_back(fee);
+        return (recipients, amounts);
+    }
+
+    let addrs: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::SplitAddrs)
+        .unwrap_or_else(|| Vec::new(env));
+    if addrs.is_empty() {
+        token_client.transfer(payer, &config.collector, &fee);
+        recipients.push_back(config.collector);
+        amounts.push_back(fee);
+        return (recipients, amounts);
+    }
+
+    let bps: Vec<u32> = env.storage().instance().get(&DataKey::SplitBps).unwrap();
+    let mut distributed = 0i128;
+    let last = addrs.len() - 1;
+    for i in 0..addrs.len() {
+        let addr = addrs.get(i).unwrap();
+        let amount = if i == last {
+            fee - distributed
+        } else {
+            fee * bps.get(i).unwrap() as i128 / BPS_DENOM
+        };
+        distributed += amount;
+        token_client.transfer(payer, &addr, &amount);
+        recipients.push_back(addr);
+        amounts.push_back(amount);
+    }
+    (recipients, amounts)
+}
+
+// ── Tiers and volume brackets ───────────────────────────────────────
+
+pub fn set_tier_discount(env: &Env, tier: u32, discount_bps: u32) {
+    assert!(discount_bps <= 10_000, "discount_bps must be <= 10000");
+    env.storage()
+        .instance()
+        .set(&DataKey::TierDiscount(tier), &discount_bps);
+}
+
+fn get_tier_discount(env: &Env, tier: u32) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TierDiscount(tier))
+        .unwrap_or(0)
+}
+
+pub fn set_business_tier(env: &Env, business: &Address, tier: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::BusinessTier(business.clone()), &tier);
+}
+
+pub fn get_business_tier(env: &Env, business: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BusinessTier(business.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_volume_brackets(env: &Env, thresholds: &Vec<u64>, discounts: &Vec<u32>) {
+    assert_eq!(
+        thresholds.len(),
+        discounts.len(),
+        "thresholds and discounts must be equal length"
+    );
+    let mut prev: Option<u64> = None;
+    for t in thresholds.iter() {
+        if let Some(p) = prev {
+            assert!(t > p, "thresholds must be strictly ascending");
+        }
+        prev = Some(t);
+    }
+    for d in discounts.iter() {
+        assert!(d <= 10_000, "discount_bps must be <= 10000");
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::VolumeThresholds, thresholds);
+    env.storage()
+        .instance()
+        .set(&DataKey::VolumeDiscounts, discounts);
+}
+
+/// Resolve the volume discount for a business given its attestation count.
+fn volume_discount(env: &Env, count: u64) -> u32 {
+    let thresholds: Vec<u64> = env
+        .storage()
+        .instance()
+        .get(&DataKey::VolumeThresholds)
+        .unwrap_or_else(|| Vec::new(env));
+    let discounts: Vec<u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey::VolumeDiscounts)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut discount = 0u32;
+    for i in 0..thresholds.len() {
+        if count >= thresholds.get(i).unwrap() {
+            discount = discounts.get(i).unwrap();
+        }
+    }
+    discount
+}
+
+// ── Business volume counters ────────────────────────────────────────
+
+pub fn increment_business_count(env: &Env, business: &Address) {
+    let count = get_business_count(env, business) + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::BusinessCount(business.clone()), &count);
+}
+
+pub fn get_business_count(env: &Env, business: &Address) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BusinessCount(business.clone()))
+        .unwrap_or(0)
+}
+
+// ── Fee computation ─────────────────────────────────────────────────
+
+/// Combine a base fee with tier and volume discounts (both basis points).
+///
+/// Discounts compound: the tier discount is applied first, then the volume
+/// discount is applied to the remainder.
+pub fn compute_fee(base_fee: i128, tier_discount_bps: u32, volume_discount_bps: u32) -> i128 {
+    let after_tier = base_fee - base_fee * tier_discount_bps as i128 / BPS_DENOM;
+    after_tier - after_tier * volume_discount_bps as i128 / BPS_DENOM
+}
+
+/// Resolve the base fee for the token a business has chosen to settle in.
+///
+/// `None` uses the default [`FeeConfig`] token; `Some(token)` must name a
+/// registered fee token. Returns `None` when fees are disabled or unconfigured.
+fn effective_base(env: &Env, pay_token: &Option<Address>) -> Option<i128> {
+    // The master switch gates every settlement token, not just the default.
+    let config = get_fee_config(env)?;
+    if !config.enabled {
+        return None;
+    }
+    match pay_token {
+        Some(token) => {
+            let entry = get_fee_token(env, token).expect("fee token not registered");
+            Some(entry.base_fee * entry.multiplier_bps as i128 / BPS_DENOM)
+        }
+        None => Some(config.base_fee),
+    }
+}
+
+/// Size-proportional component added to the base before discounts.
+///
+/// Mirrors the Soroban host's per-1 KiB write-fee model:
+/// `ceil(payload_bytes / 1024) * fee_per_1kb + leaf_count * fee_per_entry`.
+/// Reads the size schedule from the default [`FeeConfig`]; zero if unset. When
+/// settling in a registered alt token the schedule (denominated in the default
+/// token) is scaled by that token's `multiplier_bps`, so the size component
+/// shares the selected token's denomination with the base fee.
+fn size_component(env: &Env, pay_token: &Option<Address>, payload_bytes: u32, leaf_count: u32) -> i128 {
+    let config = match get_fee_config(env) {
+        Some(config) => config,
+        None => return 0,
+    };
+    let kb = (payload_bytes as i128 + 1023) / 1024;
+    let raw = kb * config.fee_per_1kb as i128 + leaf_count as i128 * config.fee_per_entry as i128;
+    match pay_token {
+        Some(token) => {
+            let entry = get_fee_token(env, token).expect("fee token not registered");
+            raw * entry.multiplier_bps as i128 / BPS_DENOM
+        }
+        None => raw,
+    }
+}
+
+/// Quote the fee a business would pay for its next attestation in `pay_token`,
+/// for an attestation of the given payload size and leaf count.
+pub fn calculate_fee(
+    env: &Env,
+    business: &Address,
+    pay_token: &Option<Address>,
+    payload_bytes: u32,
+    leaf_count: u32,
+) -> i128 {
+    let base = match effective_base(env, pay_token) {
+        Some(base) => base,
+        None => return 0,
+    };
+    let base = base + size_component(env, pay_token, payload_bytes, leaf_count);
+    let tier = get_business_tier(env, business);
+    let tier_discount = get_tier_discount(env, tier);
+    let count = get_business_count(env, business);
+    let volume = volume_discount(env, count);
+    compute_fee(base, tier_discount, volume)
+}
+
+/// Charge the calculated fee, settling in `pay_token` and debiting `payer`.
+///
+/// The fee amount (tier and volume discounts) is keyed on `business`, but the
+/// tokens are moved from `payer` — which may be a sponsor distinct from the
+/// attesting business. Returns the amount charged (0 if fees are disabled or
+/// unconfigured). Panics if `pay_token` names an unregistered token.
+pub fn collect_fee(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    payer: &Address,
+    pay_token: &Option<Address>,
+    payload_bytes: u32,
+    leaf_count: u32,
+) -> i128 {
+    let fee = calculate_fee(env, business, pay_token, payload_bytes, leaf_count);
+    if fee == 0 {
+        return 0;
+    }
+
+    let config = get_fee_config(env).expect("fees not configured");
+    let token = match pay_token {
+        // Registration already checked by `calculate_fee`.
+        Some(token) => token.clone(),
+        None => config.token.clone(),
+    };
+    // Pay the effective recipient(s) — per-business override, split, or the
+    // global collector — and capture exactly what each received so a refund
+    // can reverse the charge proportionally.
+    let (recipients, amounts) = distribute_fee(env, business, payer, &token, fee);
+
+    // Record the charge in the per-period ledger for later reconciliation
+    // and possible refund on supersession.
+    let entry = FeeLedgerEntry {
+        charged: fee,
+        refunded: 0,
+        recipients,
+        amounts,
+        token,
+        payer: payer.clone(),
+        charged_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeLedger(business.clone(), period.clone()), &entry);
+    bump_period_total(env, period, fee);
+    fee
+}
+
+// ── Per-period fee ledger ───────────────────────────────────────────
+
+pub fn set_refund_policy(env: &Env, policy: &RefundPolicy) {
+    assert!(policy.refund_bps <= 10_000, "refund_bps must be <= 10000");
+    env.storage().instance().set(&DataKey::RefundPolicy, policy);
+}
+
+fn get_refund_policy(env: &Env) -> Option<RefundPolicy> {
+    env.storage().instance().get(&DataKey::RefundPolicy)
+}
+
+pub fn get_fee_ledger(env: &Env, business: &Address, period: &String) -> Option<FeeLedgerEntry> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeLedger(business.clone(), period.clone()))
+}
+
+/// Net fees collected for a period (total charged minus refunded).
+pub fn get_fees_collected(env: &Env, period: &String) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PeriodTotal(period.clone()))
+        .unwrap_or(0)
+}
+
+fn bump_period_total(env: &Env, period: &String, delta: i128) {
+    let total = get_fees_collected(env, period) + delta;
+    env.storage()
+        .instance()
+        .set(&DataKey::PeriodTotal(period.clone()), &total);
+}
+
+/// Refund part of a prior fee when its attestation is superseded.
+///
+/// Returns the amount refunded (0 when no ledger entry exists, no policy is
+/// set, or the refund window has closed). The refund is capped at the unused
+/// share of the original charge and can never make `refunded` exceed
+/// `charged`. Each recipient authorizes its own proportional reverse transfer.
+pub fn refund_on_supersede(env: &Env, business: &Address, period: &String) -> i128 {
+    let mut entry = match get_fee_ledger(env, business, period) {
+        Some(entry) => entry,
+        None => return 0,
+    };
+    let policy = match get_refund_policy(env) {
+        Some(policy) => policy,
+        None => return 0,
+    };
+
+    // Window closed — refunds disabled.
+    if env.ledger().timestamp() > entry.charged_at + policy.window_secs {
+        return 0;
+    }
+
+    let allowed = entry.charged * policy.refund_bps as i128 / BPS_DENOM;
+    let refund = (allowed - entry.refunded).min(entry.charged - entry.refunded);
+    if refund <= 0 {
+        return 0;
+    }
+
+    // Reverse the charge from each recipient in proportion to what it was
+    // paid, so a split collector is never debited for another's share. The
+    // last recipient absorbs any rounding remainder.
+    let token_client = soroban_sdk::token::Client::new(env, &entry.token);
+    let n = entry.recipients.len();
+    let mut reversed = 0i128;
+    for i in 0..n {
+        let recipient = entry.recipients.get(i).unwrap();
+        let share = if i == n - 1 {
+            refund - reversed
+        } else {
+            refund * entry.amounts.get(i).unwrap() / entry.charged
+        };
+        reversed += share;
+        if share > 0 {
+            recipient.require_auth();
+            token_client.transfer(&recipient, &entry.payer, &share);
+        }
+    }
+
+    entry.refunded += refund;
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeLedger(business.clone(), period.clone()), &entry);
+    bump_period_total(env, period, -refund);
+    refund
+}
@@ -0,0 +1,289 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, String,
+};
+
+/// Register a Stellar asset and return its address plus an admin client for
+/// minting balances to fee payers.
+fn make_token<'a>(env: &Env) -> (Address, token::StellarAssetClient<'a>) {
+    let admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    (
+        sac.address(),
+        token::StellarAssetClient::new(env, &sac.address()),
+    )
+}
+
+fn setup<'a>() -> (Env, AttestationContractClient<'a>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let collector = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, admin, collector)
+}
+
+fn root(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0u8; 32])
+}
+
+#[test]
+fn default_token_fee_charged_to_collector() {
+    let (env, client, _admin, collector) = setup();
+    let (token, minter) = make_token(&env);
+    let token_client = token::Client::new(&env, &token);
+    let business = Address::generate(&env);
+
+    client.configure_fees(&token, &collector, &1_000i128, &0i64, &0i64, &true);
+    minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(&business, &period, &root(&env), &0u64, &1u32, &None, &None, &0u32, &0u32);
+
+    assert_eq!(token_client.balance(&collector), 1_000);
+    assert_eq!(token_client.balance(&business), 9_000);
+    let ledger = client.get_fee_ledger(&business, &period).unwrap();
+    assert_eq!(ledger.charged, 1_000);
+    assert_eq!(client.get_fees_collected(&period), 1_000);
+}
+
+#[test]
+fn alt_token_applies_multiplier() {
+    let (env, client, _admin, collector) = setup();
+    let (default_token, _) = make_token(&env);
+    let (alt_token, alt_minter) = make_token(&env);
+    let alt_client = token::Client::new(&env, &alt_token);
+    let business = Address::generate(&env);
+
+    client.configure_fees(&default_token, &collector, &1_000i128, &0i64, &0i64, &true);
+    // 2000 base scaled by 50% → 1000 in the alt token's units.
+    client.add_fee_token(&alt_token, &2_000i128, &5_000u32);
+    alt_minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(
+        &business,
+        &period,
+        &root(&env),
+        &0u64,
+        &1u32,
+        &Some(alt_token.clone()),
+        &None,
+        &0u32,
+        &0u32,
+    );
+
+    assert_eq!(alt_client.balance(&collector), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "fee token not registered")]
+fn unregistered_pay_token_rejected() {
+    let (env, client, _admin, collector) = setup();
+    let (default_token, _) = make_token(&env);
+    let business = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.configure_fees(&default_token, &collector, &1_000i128, &0i64, &0i64, &true);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(
+        &business,
+        &period,
+        &root(&env),
+        &0u64,
+        &1u32,
+        &Some(stranger),
+        &None,
+        &0u32,
+        &0u32,
+    );
+}
+
+#[test]
+fn sponsor_pays_fee_for_business() {
+    let (env, client, _admin, collector) = setup();
+    let (token, minter) = make_token(&env);
+    let token_client = token::Client::new(&env, &token);
+    let business = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+
+    client.configure_fees(&token, &collector, &1_000i128, &0i64, &0i64, &true);
+    minter.mint(&sponsor, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(
+        &business,
+        &period,
+        &root(&env),
+        &0u64,
+        &1u32,
+        &None,
+        &Some(sponsor.clone()),
+        &0u32,
+        &0u32,
+    );
+
+    assert_eq!(token_client.balance(&collector), 1_000);
+    assert_eq!(token_client.balance(&sponsor), 9_000);
+    assert_eq!(token_client.balance(&business), 0);
+    let stored = client.get_attestation(&business, &period).unwrap();
+    assert_eq!(stored.3, 1_000);
+    assert_eq!(stored.4, sponsor);
+}
+
+#[test]
+fn size_component_scales_fee() {
+    let (env, client, _admin, collector) = setup();
+    let (token, minter) = make_token(&env);
+    let token_client = token::Client::new(&env, &token);
+    let business = Address::generate(&env);
+
+    client.configure_fees(&token, &collector, &100i128, &10i64, &5i64, &true);
+    minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    // 2 KiB → 2 * 10, plus 3 leaves → 3 * 5, on top of the 100 base.
+    client.submit_attestation(&business, &period, &root(&env), &0u64, &1u32, &None, &None, &2_048u32, &3u32);
+
+    assert_eq!(token_client.balance(&collector), 135);
+}
+
+#[test]
+fn alt_token_size_component_scaled_by_multiplier() {
+    let (env, client, _admin, collector) = setup();
+    let (default_token, _) = make_token(&env);
+    let (alt_token, alt_minter) = make_token(&env);
+    let alt_client = token::Client::new(&env, &alt_token);
+    let business = Address::generate(&env);
+
+    // Default-token size schedule: 10 per KiB, 5 per leaf.
+    client.configure_fees(&default_token, &collector, &1_000i128, &10i64, &5i64, &true);
+    // Alt token: base 2000 scaled 50% → 1000 base in alt units.
+    client.add_fee_token(&alt_token, &2_000i128, &5_000u32);
+    alt_minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    // 2 KiB + 3 leaves → raw 35, scaled by 50% → 17, on top of the 1000 base.
+    client.submit_attestation(
+        &business,
+        &period,
+        &root(&env),
+        &0u64,
+        &1u32,
+        &Some(alt_token.clone()),
+        &None,
+        &2_048u32,
+        &3u32,
+    );
+
+    assert_eq!(alt_client.balance(&collector), 1_017);
+}
+
+#[test]
+fn business_collector_override_receives_all() {
+    let (env, client, _admin, collector) = setup();
+    let (token, minter) = make_token(&env);
+    let token_client = token::Client::new(&env, &token);
+    let business = Address::generate(&env);
+    let override_collector = Address::generate(&env);
+
+    client.configure_fees(&token, &collector, &1_000i128, &0i64, &0i64, &true);
+    client.set_business_collector(&business, &override_collector);
+    minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(&business, &period, &root(&env), &0u64, &1u32, &None, &None, &0u32, &0u32);
+
+    assert_eq!(token_client.balance(&override_collector), 1_000);
+    assert_eq!(token_client.balance(&collector), 0);
+}
+
+#[test]
+fn split_distributes_by_basis_points() {
+    let (env, client, _admin, collector) = setup();
+    let (token, minter) = make_token(&env);
+    let token_client = token::Client::new(&env, &token);
+    let business = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    client.configure_fees(&token, &collector, &1_000i128, &0i64, &0i64, &true);
+    client.set_fee_split(&vec![&env, a.clone(), b.clone()], &vec![&env, 6_000u32, 4_000u32]);
+    minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(&business, &period, &root(&env), &0u64, &1u32, &None, &None, &0u32, &0u32);
+
+    assert_eq!(token_client.balance(&a), 600);
+    assert_eq!(token_client.balance(&b), 400);
+    let ledger = client.get_fee_ledger(&business, &period).unwrap();
+    assert_eq!(ledger.recipients.len(), 2);
+}
+
+#[test]
+fn refund_reverses_split_proportionally() {
+    let (env, client, _admin, collector) = setup();
+    env.ledger().set_timestamp(100);
+    let (token, minter) = make_token(&env);
+    let token_client = token::Client::new(&env, &token);
+    let business = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    client.configure_fees(&token, &collector, &1_000i128, &0i64, &0i64, &true);
+    client.set_fee_split(&vec![&env, a.clone(), b.clone()], &vec![&env, 6_000u32, 4_000u32]);
+    client.set_refund_policy(&1_000u64, &5_000u32);
+    minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(&business, &period, &root(&env), &100u64, &1u32, &None, &None, &0u32, &0u32);
+
+    // Supersede within the refund window: half is refunded, drawn from each
+    // recipient in proportion to what it was paid (300 from a, 200 from b).
+    env.ledger().set_timestamp(200);
+    let new_root = BytesN::from_array(&env, &[7u8; 32]);
+    client.supersede_attestation(&business, &period, &new_root, &200u64);
+
+    assert_eq!(token_client.balance(&a), 300);
+    assert_eq!(token_client.balance(&b), 200);
+    assert_eq!(token_client.balance(&business), 9_500);
+    let ledger = client.get_fee_ledger(&business, &period).unwrap();
+    assert_eq!(ledger.refunded, 500);
+    assert_eq!(client.get_fees_collected(&period), 500);
+}
+
+#[test]
+fn disabled_fees_skip_registered_alt_token() {
+    let (env, client, _admin, collector) = setup();
+    let (default_token, _) = make_token(&env);
+    let (alt_token, alt_minter) = make_token(&env);
+    let alt_client = token::Client::new(&env, &alt_token);
+    let business = Address::generate(&env);
+
+    client.configure_fees(&default_token, &collector, &1_000i128, &0i64, &0i64, &true);
+    client.add_fee_token(&alt_token, &2_000i128, &5_000u32);
+    client.set_fee_enabled(&false);
+    alt_minter.mint(&business, &10_000);
+
+    let period = String::from_str(&env, "2026-02");
+    client.submit_attestation(
+        &business,
+        &period,
+        &root(&env),
+        &0u64,
+        &1u32,
+        &Some(alt_token.clone()),
+        &None,
+        &0u32,
+        &0u32,
+    );
+
+    assert_eq!(alt_client.balance(&collector), 0);
+    assert_eq!(alt_client.balance(&business), 10_000);
+    assert!(client.get_fee_ledger(&business, &period).is_none());
+}
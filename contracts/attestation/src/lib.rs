@@ -2,7 +2,7 @@
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 pub mod dynamic_fees;
-pub use dynamic_fees::{compute_fee, DataKey, FeeConfig};
+pub use dynamic_fees::{compute_fee, DataKey, FeeConfig, FeeLedgerEntry, FeeToken, RefundPolicy};
 
 #[cfg(test)]
 mod test;
@@ -12,6 +12,8 @@ mod dynamic_fees_test;
 const ANOMALY_KEY_TAG: u32 = 1;
 const ADMIN_KEY_TAG: (u32,) = (2,);
 const AUTHORIZED_KEY_TAG: u32 = 3;
+const REVENUE_KEY_TAG: u32 = 4;
+const REVOKED_KEY_TAG: u32 = 5;
 const ANOMALY_SCORE_MAX: u32 = 100;
 
 #[contract]
@@ -40,25 +42,58 @@ impl AttestationContract {
     /// * `token`    – Token contract address for fee payment.
     /// * `collector` – Address that receives fees.
     /// * `base_fee` – Base fee in token smallest units.
+    /// * `fee_per_1kb` – Size fee per 1 KiB of attestation payload.
+    /// * `fee_per_entry` – Size fee per committed Merkle leaf.
     /// * `enabled`  – Master switch for fee collection.
     pub fn configure_fees(
         env: Env,
         token: Address,
         collector: Address,
         base_fee: i128,
+        fee_per_1kb: i64,
+        fee_per_entry: i64,
         enabled: bool,
     ) {
         dynamic_fees::require_admin(&env);
         assert!(base_fee >= 0, "base_fee must be non-negative");
+        assert!(fee_per_1kb >= 0, "fee_per_1kb must be non-negative");
+        assert!(fee_per_entry >= 0, "fee_per_entry must be non-negative");
         let config = FeeConfig {
             token,
             collector,
             base_fee,
+            fee_per_1kb,
+            fee_per_entry,
             enabled,
         };
         dynamic_fees::set_fee_config(&env, &config);
     }
 
+    /// Register an accepted fee token with its own base fee and multiplier.
+    ///
+    /// Businesses may then settle an attestation in this token by passing its
+    /// address as `pay_token` to [`submit_attestation`]. The multiplier scales
+    /// `base_fee` (basis points) to normalize across tokens with differing
+    /// decimals or value. Re-registering an existing token updates its config.
+    ///
+    /// * `multiplier_bps` – scaling factor in basis points applied to `base_fee`.
+    pub fn add_fee_token(env: Env, token: Address, base_fee: i128, multiplier_bps: u32) {
+        dynamic_fees::require_admin(&env);
+        assert!(base_fee >= 0, "base_fee must be non-negative");
+        let entry = FeeToken {
+            token,
+            base_fee,
+            multiplier_bps,
+        };
+        dynamic_fees::add_fee_token(&env, &entry);
+    }
+
+    /// Remove a token from the accepted fee-token set.
+    pub fn remove_fee_token(env: Env, token: Address) {
+        dynamic_fees::require_admin(&env);
+        dynamic_fees::remove_fee_token(&env, &token);
+    }
+
     /// Set the discount (in basis points, 0–10 000) for a tier level.
     ///
     /// * Tier 0 = Standard (default for all businesses).
@@ -90,6 +125,39 @@ impl AttestationContract {
         dynamic_fees::set_volume_brackets(&env, &thresholds, &discounts);
     }
 
+    /// Override the fee collector for a specific business.
+    ///
+    /// The override takes precedence over the global collector and any split
+    /// spec when routing that business's fees.
+    pub fn set_business_collector(env: Env, business: Address, collector: Address) {
+        dynamic_fees::require_admin(&env);
+        dynamic_fees::set_business_collector(&env, &business, &collector);
+    }
+
+    /// Configure a global split-collector spec.
+    ///
+    /// `addrs` and `bps` must be equal-length and non-empty; the shares (basis
+    /// points) must sum to 10 000. When set, fees for businesses without a
+    /// per-business override are apportioned across these recipients.
+    pub fn set_fee_split(env: Env, addrs: Vec<Address>, bps: Vec<u32>) {
+        dynamic_fees::require_admin(&env);
+        dynamic_fees::set_fee_split(&env, &addrs, &bps);
+    }
+
+    /// Configure the refund terms applied when an attestation is superseded.
+    ///
+    /// * `window_secs` – seconds after the charge during which a refund is
+    ///   allowed; once elapsed, supersession issues no refund.
+    /// * `refund_bps` – share of the original fee refundable (0–10 000).
+    pub fn set_refund_policy(env: Env, window_secs: u64, refund_bps: u32) {
+        dynamic_fees::require_admin(&env);
+        let policy = RefundPolicy {
+            window_secs,
+            refund_bps,
+        };
+        dynamic_fees::set_refund_policy(&env, &policy);
+    }
+
     /// Toggle fee collection on or off without changing other config.
     pub fn set_fee_enabled(env: Env, enabled: bool) {
         dynamic_fees::require_admin(&env);
@@ -108,6 +176,20 @@ impl AttestationContract {
     /// in the configured token. The business address must authorize the
     /// call.
     ///
+    /// `pay_token` selects which settlement token to charge: `None` uses the
+    /// default [`FeeConfig`] token, `Some(addr)` must name a token previously
+    /// registered with [`add_fee_token`]. Unregistered tokens are rejected.
+    ///
+    /// `fee_payer` optionally decouples the fee-paying account from the
+    /// business: when `Some(addr)`, that address must also authorize the call
+    /// and the fee is debited from it (enabling platforms or lenders to
+    /// sponsor attestations), while volume counting and tier lookup still key
+    /// on `business`. When `None`, the business pays its own fee.
+    ///
+    /// `payload_bytes` and `leaf_count` describe the committed data and add a
+    /// size-proportional component to the base fee (before discounts), so
+    /// larger Merkle commitments cost more.
+    ///
     /// Panics if an attestation already exists for the same
     /// (business, period).
     pub fn submit_attestation(
@@ -117,32 +199,55 @@ impl AttestationContract {
         merkle_root: BytesN<32>,
         timestamp: u64,
         version: u32,
+        pay_token: Option<Address>,
+        fee_payer: Option<Address>,
+        payload_bytes: u32,
+        leaf_count: u32,
     ) {
         business.require_auth();
 
-        let key = DataKey::Attestation(business.clone(), period);
+        let key = DataKey::Attestation(business.clone(), period.clone());
         if env.storage().instance().has(&key) {
             panic!("attestation already exists for this business and period");
         }
 
-        // Collect fee (0 if fees disabled or not configured).
-        let fee_paid = dynamic_fees::collect_fee(&env, &business);
+        // Resolve who settles the fee: the sponsor if supplied, else the
+        // business itself. A sponsor must independently authorize.
+        let payer = match &fee_payer {
+            Some(sponsor) => {
+                sponsor.require_auth();
+                sponsor.clone()
+            }
+            None => business.clone(),
+        };
+
+        // Collect fee (0 if fees disabled or not configured). Discounts are
+        // keyed on `business`; tokens move from `payer`.
+        let fee_paid = dynamic_fees::collect_fee(
+            &env,
+            &business,
+            &period,
+            &payer,
+            &pay_token,
+            payload_bytes,
+            leaf_count,
+        );
 
         // Track volume for future discount calculations.
         dynamic_fees::increment_business_count(&env, &business);
 
-        let data = (merkle_root, timestamp, version, fee_paid);
+        let data = (merkle_root, timestamp, version, fee_paid, payer);
         env.storage().instance().set(&key, &data);
     }
 
     /// Return stored attestation for (business, period), if any.
     ///
-    /// Returns `(merkle_root, timestamp, version, fee_paid)`.
+    /// Returns `(merkle_root, timestamp, version, fee_paid, fee_payer)`.
     pub fn get_attestation(
         env: Env,
         business: Address,
         period: String,
-    ) -> Option<(BytesN<32>, u64, u32, i128)> {
+    ) -> Option<(BytesN<32>, u64, u32, i128, Address)> {
         let key = DataKey::Attestation(business, period);
         env.storage().instance().get(&key)
     }
@@ -154,7 +259,7 @@ impl AttestationContract {
         period: String,
         merkle_root: BytesN<32>,
     ) -> bool {
-        if let Some((stored_root, _ts, _ver, _fee)) =
+        if let Some((stored_root, _ts, _ver, _fee, _payer)) =
             Self::get_attestation(env.clone(), business, period)
         {
             stored_root == merkle_root
@@ -163,6 +268,37 @@ impl AttestationContract {
         }
     }
 
+    /// Supersede an existing attestation with a new Merkle root.
+    ///
+    /// Stores `new_merkle_root` at a bumped `version` for the same
+    /// (business, period) and, when a refund policy is configured and the
+    /// refund window is still open, refunds part of the previously charged fee
+    /// to whoever paid it. The business must authorize the call.
+    ///
+    /// Panics if no attestation exists for (business, period).
+    pub fn supersede_attestation(
+        env: Env,
+        business: Address,
+        period: String,
+        new_merkle_root: BytesN<32>,
+        timestamp: u64,
+    ) {
+        business.require_auth();
+
+        let key = DataKey::Attestation(business.clone(), period.clone());
+        let (_root, _ts, version, fee_paid, payer): (BytesN<32>, u64, u32, i128, Address) = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("attestation does not exist for this business and period");
+
+        // Refund part of the prior fee if still within the window.
+        dynamic_fees::refund_on_supersede(&env, &business, &period);
+
+        let data = (new_merkle_root, timestamp, version + 1, fee_paid, payer);
+        env.storage().instance().set(&key, &data);
+    }
+
     /// One-time setup of the admin address. Admin is the single authorized updater of the
     /// authorized-analytics set. Anomaly data is stored under a separate instance key and
     /// never modifies attestation (merkle root, timestamp, version) storage.
@@ -241,11 +377,64 @@ impl AttestationContract {
         let key = (ANOMALY_KEY_TAG, business, period);
         env.storage().instance().get(&key)
     }
-}
 
-mod test;
-#[cfg(test)]
-mod anomaly_test;
+    /// Records the attested revenue figure for an existing attestation.
+    ///
+    /// Only addresses in the authorized-analytics set may call this; the
+    /// updater must pass its address and authorize. Downstream contracts (the
+    /// revenue-bond contract) read this figure to check a self-reported
+    /// revenue against the on-chain attestation. Panics if the attestation is
+    /// missing or `revenue` is negative.
+    pub fn set_attested_revenue(
+        env: Env,
+        updater: Address,
+        business: Address,
+        period: String,
+        revenue: i128,
+    ) {
+        updater.require_auth();
+        let key_auth = (AUTHORIZED_KEY_TAG, updater);
+        if !env.storage().instance().has(&key_auth) {
+            panic!("updater not authorized");
+        }
+        let attest_key = DataKey::Attestation(business.clone(), period.clone());
+        if !env.storage().instance().has(&attest_key) {
+            panic!("attestation does not exist for this business and period");
+        }
+        assert!(revenue >= 0, "revenue must be non-negative");
+        let key = (REVENUE_KEY_TAG, business, period);
+        env.storage().instance().set(&key, &revenue);
+    }
+
+    /// Returns the attested revenue for (business, period), or 0 if none has
+    /// been recorded. Consumed by the revenue-bond contract's `redeem`.
+    pub fn get_attested_revenue(env: Env, business: Address, period: String) -> i128 {
+        let key = (REVENUE_KEY_TAG, business, period);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    /// Marks an attestation as revoked, blocking downstream redemptions.
+    ///
+    /// Authorized updaters only. Panics if the attestation is missing.
+    pub fn revoke_attestation(env: Env, updater: Address, business: Address, period: String) {
+        updater.require_auth();
+        let key_auth = (AUTHORIZED_KEY_TAG, updater);
+        if !env.storage().instance().has(&key_auth) {
+            panic!("updater not authorized");
+        }
+        let attest_key = DataKey::Attestation(business.clone(), period.clone());
+        if !env.storage().instance().has(&attest_key) {
+            panic!("attestation does not exist for this business and period");
+        }
+        let key = (REVOKED_KEY_TAG, business, period);
+        env.storage().instance().set(&key, &true);
+    }
+
+    /// Returns whether (business, period) has been revoked.
+    pub fn is_revoked(env: Env, business: Address, period: String) -> bool {
+        let key = (REVOKED_KEY_TAG, business, period);
+        env.storage().instance().get(&key).unwrap_or(false)
+    }
 
     // ── Read-only queries ───────────────────────────────────────────
 
@@ -254,9 +443,36 @@ mod anomaly_test;
         dynamic_fees::get_fee_config(&env)
     }
 
-    /// Calculate the fee a business would pay for its next attestation.
-    pub fn get_fee_quote(env: Env, business: Address) -> i128 {
-        dynamic_fees::calculate_fee(&env, &business)
+    /// Return the list of accepted fee tokens.
+    pub fn get_fee_tokens(env: Env) -> Vec<Address> {
+        dynamic_fees::get_fee_tokens(&env)
+    }
+
+    /// Calculate the fee a business would pay for its next attestation in the
+    /// given settlement token (`None` for the default token), for an
+    /// attestation of the supplied payload size and leaf count.
+    pub fn get_fee_quote(
+        env: Env,
+        business: Address,
+        pay_token: Option<Address>,
+        payload_bytes: u32,
+        leaf_count: u32,
+    ) -> i128 {
+        dynamic_fees::calculate_fee(&env, &business, &pay_token, payload_bytes, leaf_count)
+    }
+
+    /// Return the fee ledger entry for (business, period), if any.
+    pub fn get_fee_ledger(
+        env: Env,
+        business: Address,
+        period: String,
+    ) -> Option<FeeLedgerEntry> {
+        dynamic_fees::get_fee_ledger(&env, &business, &period)
+    }
+
+    /// Return the net fees collected for a period (charges minus refunds).
+    pub fn get_fees_collected(env: Env, period: String) -> i128 {
+        dynamic_fees::get_fees_collected(&env, &period)
     }
 
     /// Return the tier assigned to a business (0 if unset).
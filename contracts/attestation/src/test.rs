@@ -14,7 +14,7 @@ fn submit_and_get_attestation() {
     let timestamp = 1700000000u64;
     let version = 1u32;
 
-    client.submit_attestation(&business, &period, &root, &timestamp, &version);
+    client.submit_attestation(&business, &period, &root, &timestamp, &version, &None, &None, &0u32, &0u32);
 
     let stored = client.get_attestation(&business, &period).unwrap();
     assert_eq!(stored.0, root);
@@ -31,7 +31,7 @@ fn verify_attestation() {
     let business = Address::generate(&env);
     let period = String::from_str(&env, "2026-02");
     let root = BytesN::from_array(&env, &[2u8; 32]);
-    client.submit_attestation(&business, &period, &root, &1700000000u64, &1u32);
+    client.submit_attestation(&business, &period, &root, &1700000000u64, &1u32, &None, &None, &0u32, &0u32);
 
     assert!(client.verify_attestation(&business, &period, &root));
     let other_root = BytesN::from_array(&env, &[3u8; 32]);
@@ -49,6 +49,6 @@ fn duplicate_attestation_panics() {
     let period = String::from_str(&env, "2026-02");
     let root = BytesN::from_array(&env, &[0u8; 32]);
 
-    client.submit_attestation(&business, &period, &root, &1700000000u64, &1u32);
-    client.submit_attestation(&business, &period, &root, &1700000001u64, &1u32);
+    client.submit_attestation(&business, &period, &root, &1700000000u64, &1u32, &None, &None, &0u32, &0u32);
+    client.submit_attestation(&business, &period, &root, &1700000001u64, &1u32, &None, &None, &0u32, &0u32);
 }
@@ -13,7 +13,7 @@
 //! - Default handling and risk management
 
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, String, Vec};
 
 /// Attestation client: WASM import for wasm32, crate for tests.
 #[cfg(target_arch = "wasm32")]
@@ -25,13 +25,18 @@ mod attestation_import {
 }
 #[cfg(not(target_arch = "wasm32"))]
 mod attestation_import {
-    use soroban_sdk::{Address, BytesN, Env, String};
-    
+    use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Symbol};
+
+    /// Native client that cross-invokes a registered `AttestationContract`.
+    ///
+    /// Outside wasm the `contractimport!` client is unavailable, so redemption
+    /// verification dispatches by symbol against the live attestation contract
+    /// at `address` — the same methods the generated wasm client exposes.
     pub struct AttestationContractClient {
         env: Env,
         address: Address,
     }
-    
+
     impl AttestationContractClient {
         pub fn new(env: &Env, address: &Address) -> Self {
             Self {
@@ -39,30 +44,33 @@ mod attestation_import {
                 address: address.clone(),
             }
         }
-        
-        #[cfg(test)]
-        pub fn get_attestation(&self, _business: &Address, _period: &String) -> Option<(BytesN<32>, u64, u32, i128)> {
-            Some((
-                BytesN::from_array(&self.env, &[0u8; 32]),
-                1000,
-                1,
-                0,
-            ))
-        }
-        
-        #[cfg(test)]
-        pub fn is_revoked(&self, _business: &Address, _period: &String) -> bool {
-            false
+
+        pub fn get_attestation(
+            &self,
+            business: &Address,
+            period: &String,
+        ) -> Option<(BytesN<32>, u64, u32, i128, Address)> {
+            self.env.invoke_contract(
+                &self.address,
+                &Symbol::new(&self.env, "get_attestation"),
+                (business.clone(), period.clone()).into_val(&self.env),
+            )
         }
-        
-        #[cfg(not(test))]
-        pub fn get_attestation(&self, _business: &Address, _period: &String) -> Option<(BytesN<32>, u64, u32, i128)> {
-            panic!("attestation contract not available in non-wasm32 non-test builds");
+
+        pub fn is_revoked(&self, business: &Address, period: &String) -> bool {
+            self.env.invoke_contract(
+                &self.address,
+                &Symbol::new(&self.env, "is_revoked"),
+                (business.clone(), period.clone()).into_val(&self.env),
+            )
         }
-        
-        #[cfg(not(test))]
-        pub fn is_revoked(&self, _business: &Address, _period: &String) -> bool {
-            panic!("attestation contract not available in non-wasm32 non-test builds");
+
+        pub fn get_attested_revenue(&self, business: &Address, period: &String) -> i128 {
+            self.env.invoke_contract(
+                &self.address,
+                &Symbol::new(&self.env, "get_attested_revenue"),
+                (business.clone(), period.clone()).into_val(&self.env),
+            )
         }
     }
 }
@@ -70,6 +78,10 @@ mod attestation_import {
 #[cfg(test)]
 mod test;
 
+/// Seconds in one amortization month (30 days), used to translate elapsed
+/// ledger time into the linear coupon schedule.
+const SECONDS_PER_MONTH: u64 = 30 * 24 * 60 * 60;
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub enum DataKey {
@@ -77,8 +89,12 @@ pub enum DataKey {
     NextBondId,
     Bond(u64),
     BondOwner(u64),
+    BondBeneficiary(u64),
     Redemption(u64, String),
     TotalRedeemed(u64),
+    PendingRedemption(u64, String),
+    Reserve(u64),
+    Recovery(u64),
 }
 
 /// Bond structure types
@@ -126,6 +142,23 @@ pub struct Bond {
     pub token: Address,
     pub status: BondStatus,
     pub issued_at: u64,
+    /// Ledger timestamp at which the bond reaches full maturity.
+    pub maturity_timestamp: u64,
+    /// Set once the bond is marked matured; unlocks the full face value.
+    pub matured: bool,
+    /// Share of each redemption inflow retained in the reserve (basis points).
+    pub reserve_bps: u32,
+}
+
+/// On-chain receipt of a default recovery distribution.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefaultRecovery {
+    pub bond_id: u64,
+    /// Amount paid to the owner from the reserve.
+    pub recovered: i128,
+    /// Remaining value the reserve could not cover.
+    pub shortfall: i128,
 }
 
 /// Redemption record for a specific period
@@ -139,6 +172,52 @@ pub struct RedemptionRecord {
     pub redeemed_at: u64,
 }
 
+/// Leaf predicate guarding a scheduled redemption.
+///
+/// Soroban's serialization does not support recursive `Box` trees, so a plan
+/// is expressed as a flat list of these leaves combined by a
+/// [`ConditionLogic`] rather than an arbitrarily nested `And`/`Or` tree.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RedemptionCondition {
+    /// Satisfied once the ledger time reaches the timestamp.
+    After(u64),
+    /// Satisfied once the given address co-signs via a witness.
+    Signature(Address),
+}
+
+/// How the predicates of a redemption plan combine.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum ConditionLogic {
+    /// All predicates must be satisfied (`And`).
+    And = 0,
+    /// Any single predicate suffices (`Or`).
+    Or = 1,
+}
+
+/// A witness presented to resolve predicates of a pending redemption.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Witness {
+    /// Current ledger time, satisfying `After` predicates that have elapsed.
+    Timestamp(u64),
+    /// A co-signer address, satisfying a matching `Signature` predicate.
+    Signature(Address),
+}
+
+/// A redemption claim recorded but not yet released, with its predicate set
+/// and per-predicate satisfaction state.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingRedemption {
+    pub amount: i128,
+    pub logic: ConditionLogic,
+    pub conditions: Vec<RedemptionCondition>,
+    pub satisfied: Vec<bool>,
+}
+
 #[contract]
 pub struct RevenueBondContract;
 
@@ -190,11 +269,13 @@ impl RevenueBondContract {
         maturity_periods: u32,
         attestation_contract: Address,
         token: Address,
+        reserve_bps: u32,
     ) -> u64 {
         issuer.require_auth();
-        
+
         assert!(face_value > 0, "face_value must be positive");
         assert!(revenue_share_bps <= 10000, "revenue_share_bps must be <= 10000");
+        assert!(reserve_bps <= 10000, "reserve_bps must be <= 10000");
         assert!(min_payment_per_period >= 0, "min_payment_per_period must be non-negative");
         assert!(max_payment_per_period > 0, "max_payment_per_period must be positive");
         assert!(max_payment_per_period >= min_payment_per_period, "max must be >= min");
@@ -207,6 +288,9 @@ impl RevenueBondContract {
             .get(&DataKey::NextBondId)
             .unwrap_or(0);
 
+        let issued_at = env.ledger().timestamp();
+        let maturity_timestamp = issued_at + maturity_periods as u64 * SECONDS_PER_MONTH;
+
         let bond = Bond {
             id,
             issuer: issuer.clone(),
@@ -219,12 +303,18 @@ impl RevenueBondContract {
             attestation_contract: attestation_contract.clone(),
             token: token.clone(),
             status: BondStatus::Active,
-            issued_at: env.ledger().timestamp(),
+            issued_at,
+            maturity_timestamp,
+            matured: false,
+            reserve_bps,
         };
 
         env.storage().instance().set(&DataKey::Bond(id), &bond);
         env.storage().instance().set(&DataKey::BondOwner(id), &initial_owner);
+        // Cash flows default to the owner until a beneficiary is set.
+        env.storage().instance().set(&DataKey::BondBeneficiary(id), &initial_owner);
         env.storage().instance().set(&DataKey::TotalRedeemed(id), &0i128);
+        env.storage().instance().set(&DataKey::Reserve(id), &0i128);
         env.storage().instance().set(&DataKey::NextBondId, &(id + 1));
 
         id
@@ -235,22 +325,25 @@ impl RevenueBondContract {
     /// # Arguments
     /// * `bond_id` - Bond identifier
     /// * `period` - Period identifier (e.g., "2026-02")
-    /// * `attested_revenue` - Revenue amount from attestation
+    /// * `reported_revenue` - Revenue the caller claims for the period
     ///
     /// # Lifecycle
     /// 1. Verify bond is active
     /// 2. Verify attestation exists and is not revoked
-    /// 3. Check no prior redemption for this period (prevent double-spending)
-    /// 4. Calculate redemption amount based on bond structure
-    /// 5. Transfer tokens from issuer to bond owner
-    /// 6. Record redemption
-    /// 7. Update total redeemed and check if bond is fully redeemed
+    /// 3. Fetch the attested revenue and require `reported_revenue` to not
+    ///    exceed it (the figure must be backed on-chain, not self-reported)
+    /// 4. Check no prior redemption for this period (prevent double-spending)
+    /// 5. Calculate redemption amount based on bond structure
+    /// 6. Transfer tokens from issuer to the bond's beneficiary, withholding
+    ///    the `reserve_bps` share into the bond's reserve
+    /// 7. Record redemption
+    /// 8. Update total redeemed and check if bond is fully redeemed
     ///
     /// # Risk Factors
     /// - Issuer must have sufficient token balance
     /// - Attestation must be valid and non-revoked
     /// - Revenue volatility affects redemption amounts
-    pub fn redeem(env: Env, bond_id: u64, period: String, attested_revenue: i128) {
+    pub fn redeem(env: Env, bond_id: u64, period: String, reported_revenue: i128) {
         let bond: Bond = env
             .storage()
             .instance()
@@ -258,7 +351,7 @@ impl RevenueBondContract {
             .expect("bond not found");
 
         assert_eq!(bond.status, BondStatus::Active, "bond not active");
-        assert!(attested_revenue >= 0, "attested_revenue must be non-negative");
+        assert!(reported_revenue >= 0, "reported_revenue must be non-negative");
 
         // Prevent double-redemption for the same period
         let existing: Option<RedemptionRecord> = env
@@ -278,10 +371,17 @@ impl RevenueBondContract {
             "attestation is revoked"
         );
 
+        // Require the reported figure to be backed by the on-chain attestation
+        let attested_revenue = client.get_attested_revenue(&bond.issuer, &period);
+        assert!(
+            reported_revenue <= attested_revenue,
+            "revenue not attested"
+        );
+
         // Calculate redemption amount based on bond structure
         let redemption_amount = Self::calculate_redemption(
             &bond,
-            attested_revenue,
+            reported_revenue,
         );
 
         // Check if redemption would exceed face value
@@ -294,16 +394,39 @@ impl RevenueBondContract {
         let actual_redemption = redemption_amount.min(bond.face_value - total_redeemed);
         assert!(actual_redemption >= 0, "bond already fully redeemed");
 
-        // Transfer tokens from issuer to bond owner
+        // Enforce the linear coupon schedule: cumulative redemptions may not
+        // run ahead of the amount vested by elapsed time (unless matured).
+        let vested = Self::vested_amount(&env, &bond);
+        assert!(
+            total_redeemed + actual_redemption <= vested,
+            "exceeds vested schedule"
+        );
+
+        // Transfer tokens from issuer to the bond's beneficiary, retaining a
+        // reserve cut for the default waterfall. A fraction of the redemption
+        // inflow is retained in the per-bond reserve, so the beneficiary
+        // receives the net amount and the reserve accrues the withheld share.
         if actual_redemption > 0 {
-            let owner: Address = env
+            let beneficiary: Address = env
                 .storage()
                 .instance()
-                .get(&DataKey::BondOwner(bond_id))
-                .expect("owner not found");
-            
+                .get(&DataKey::BondBeneficiary(bond_id))
+                .expect("beneficiary not found");
+
+            let reserve_cut = actual_redemption * bond.reserve_bps as i128 / 10000;
             let token_client = token::Client::new(&env, &bond.token);
-            token_client.transfer(&bond.issuer, &owner, &actual_redemption);
+            if reserve_cut > 0 {
+                token_client.transfer(&bond.issuer, &env.current_contract_address(), &reserve_cut);
+                let reserve: i128 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Reserve(bond_id))
+                    .unwrap_or(0);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::Reserve(bond_id), &(reserve + reserve_cut));
+            }
+            token_client.transfer(&bond.issuer, &beneficiary, &(actual_redemption - reserve_cut));
         }
 
         // Record redemption
@@ -332,6 +455,205 @@ impl RevenueBondContract {
         }
     }
 
+    /// Schedule a conditional redemption without releasing tokens.
+    ///
+    /// Records a claim of `amount` for the period gated behind `conditions`,
+    /// combined per `logic`. Nothing is transferred until the predicates are
+    /// resolved via [`apply_witness`]. Authorized by the current owner.
+    ///
+    /// Panics if a redemption (pending or completed) already exists for the
+    /// period, reusing the per-period double-spend guard.
+    pub fn schedule_redemption(
+        env: Env,
+        bond_id: u64,
+        period: String,
+        amount: i128,
+        logic: ConditionLogic,
+        conditions: Vec<RedemptionCondition>,
+    ) {
+        let owner: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BondOwner(bond_id))
+            .expect("bond not found");
+        owner.require_auth();
+
+        assert!(amount > 0, "amount must be positive");
+        assert!(!conditions.is_empty(), "at least one condition required");
+
+        let completed: Option<RedemptionRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Redemption(bond_id, period.clone()));
+        assert!(completed.is_none(), "already redeemed for period");
+        let pending: Option<PendingRedemption> = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingRedemption(bond_id, period.clone()));
+        assert!(pending.is_none(), "redemption already scheduled for period");
+
+        let mut satisfied = Vec::new(&env);
+        for _ in 0..conditions.len() {
+            satisfied.push_back(false);
+        }
+
+        let plan = PendingRedemption {
+            amount,
+            logic,
+            conditions,
+            satisfied,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingRedemption(bond_id, period), &plan);
+    }
+
+    /// Present a witness to resolve a scheduled redemption's predicates.
+    ///
+    /// A `Timestamp` witness satisfies every elapsed `After` predicate — the
+    /// comparison uses the ledger clock read inside the contract, not the
+    /// witness-supplied value, so the time-lock cannot be forged; a
+    /// `Signature` witness (which must `require_auth`) satisfies a matching
+    /// `Signature` predicate. Once the predicate set resolves to satisfied
+    /// under its logic, the release is verified against the period's revenue
+    /// attestation (as in [`redeem`]), then the token transfer and
+    /// `total_redeemed` bookkeeping fire exactly once and the pending claim is
+    /// cleared.
+    pub fn apply_witness(env: Env, bond_id: u64, period: String, witness: Witness) {
+        let bond: Bond = env
+            .storage()
+            .instance()
+            .get(&DataKey::Bond(bond_id))
+            .expect("bond not found");
+        assert_eq!(bond.status, BondStatus::Active, "bond not active");
+
+        let mut plan: PendingRedemption = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingRedemption(bond_id, period.clone()))
+            .expect("no scheduled redemption for period");
+
+        // Authorize signature witnesses before they can resolve a predicate.
+        if let Witness::Signature(addr) = &witness {
+            addr.require_auth();
+        }
+
+        for i in 0..plan.conditions.len() {
+            if plan.satisfied.get(i).unwrap() {
+                continue;
+            }
+            let resolved = match (&plan.conditions.get(i).unwrap(), &witness) {
+                // Evaluate `After` against the ledger clock, never the
+                // caller-supplied witness value, so the lock cannot be forged.
+                (RedemptionCondition::After(ts), Witness::Timestamp(_)) => {
+                    env.ledger().timestamp() >= *ts
+                }
+                (RedemptionCondition::Signature(a), Witness::Signature(b)) => a == b,
+                _ => false,
+            };
+            if resolved {
+                plan.satisfied.set(i, true);
+            }
+        }
+
+        let fulfilled = match plan.logic {
+            ConditionLogic::And => plan.satisfied.iter().all(|s| s),
+            ConditionLogic::Or => plan.satisfied.iter().any(|s| s),
+        };
+
+        if !fulfilled {
+            env.storage()
+                .instance()
+                .set(&DataKey::PendingRedemption(bond_id, period), &plan);
+            return;
+        }
+
+        // Plan satisfied: release exactly once, honoring the existing
+        // per-period guard, face-value cap and vesting schedule.
+        let existing: Option<RedemptionRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Redemption(bond_id, period.clone()));
+        assert!(existing.is_none(), "already redeemed for period");
+
+        let total_redeemed: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalRedeemed(bond_id))
+            .unwrap_or(0);
+        let actual_redemption = plan.amount.min(bond.face_value - total_redeemed);
+        assert!(actual_redemption >= 0, "bond already fully redeemed");
+
+        let vested = Self::vested_amount(&env, &bond);
+        assert!(
+            total_redeemed + actual_redemption <= vested,
+            "exceeds vested schedule"
+        );
+
+        // Back the release with the same on-chain revenue check `redeem`
+        // enforces: the attestation must exist, not be revoked, and the
+        // released amount may not exceed what the attested revenue supports.
+        let client =
+            attestation_import::AttestationContractClient::new(&env, &bond.attestation_contract);
+        assert!(
+            client.get_attestation(&bond.issuer, &period).is_some(),
+            "attestation not found"
+        );
+        assert!(
+            !client.is_revoked(&bond.issuer, &period),
+            "attestation is revoked"
+        );
+        let attested_revenue = client.get_attested_revenue(&bond.issuer, &period);
+        assert!(
+            actual_redemption <= Self::calculate_redemption(&bond, attested_revenue),
+            "revenue not attested"
+        );
+
+        if actual_redemption > 0 {
+            let beneficiary: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::BondBeneficiary(bond_id))
+                .expect("beneficiary not found");
+            let reserve_cut = actual_redemption * bond.reserve_bps as i128 / 10000;
+            let token_client = token::Client::new(&env, &bond.token);
+            if reserve_cut > 0 {
+                token_client.transfer(&bond.issuer, &env.current_contract_address(), &reserve_cut);
+                let reserve: i128 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Reserve(bond_id))
+                    .unwrap_or(0);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::Reserve(bond_id), &(reserve + reserve_cut));
+            }
+            token_client.transfer(&bond.issuer, &beneficiary, &(actual_redemption - reserve_cut));
+        }
+
+        let redemption = RedemptionRecord {
+            bond_id,
+            period: period.clone(),
+            attested_revenue,
+            redemption_amount: actual_redemption,
+            redeemed_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::Redemption(bond_id, period.clone()), &redemption);
+        env.storage()
+            .instance()
+            .remove(&DataKey::PendingRedemption(bond_id, period));
+
+        let new_total = total_redeemed + actual_redemption;
+        env.storage().instance().set(&DataKey::TotalRedeemed(bond_id), &new_total);
+        if new_total >= bond.face_value {
+            let mut updated_bond = bond;
+            updated_bond.status = BondStatus::FullyRedeemed;
+            env.storage().instance().set(&DataKey::Bond(bond_id), &updated_bond);
+        }
+    }
+
     /// Calculate redemption amount based on bond structure and revenue.
     fn calculate_redemption(bond: &Bond, attested_revenue: i128) -> i128 {
         match bond.structure {
@@ -357,6 +679,49 @@ impl RevenueBondContract {
         }
     }
 
+    /// Cumulative amount unlockable for a bond at the current ledger time.
+    ///
+    /// Linear amortization: `face_value * elapsed_months / maturity_periods`,
+    /// clamped to `face_value`. A matured bond (or one past its
+    /// `maturity_timestamp`) vests its full face value.
+    fn vested_amount(env: &Env, bond: &Bond) -> i128 {
+        let now = env.ledger().timestamp();
+        if bond.matured || now >= bond.maturity_timestamp {
+            return bond.face_value;
+        }
+        let elapsed_months = ((now - bond.issued_at) / SECONDS_PER_MONTH)
+            .min(bond.maturity_periods as u64);
+        bond.face_value * elapsed_months as i128 / bond.maturity_periods as i128
+    }
+
+    /// Get the cumulative amount currently unlockable under the schedule.
+    pub fn get_vested_amount(env: Env, bond_id: u64) -> i128 {
+        let bond: Bond = env
+            .storage()
+            .instance()
+            .get(&DataKey::Bond(bond_id))
+            .expect("bond not found");
+        Self::vested_amount(&env, &bond)
+    }
+
+    /// Mark a bond as matured, unlocking its full face value for redemption.
+    ///
+    /// Permitted only once the bond is past its `maturity_timestamp`.
+    pub fn mark_matured(env: Env, bond_id: u64) {
+        let mut bond: Bond = env
+            .storage()
+            .instance()
+            .get(&DataKey::Bond(bond_id))
+            .expect("bond not found");
+
+        assert!(
+            env.ledger().timestamp() >= bond.maturity_timestamp,
+            "bond not yet matured"
+        );
+        bond.matured = true;
+        env.storage().instance().set(&DataKey::Bond(bond_id), &bond);
+    }
+
     /// Transfer bond ownership.
     ///
     /// # Arguments
@@ -378,6 +743,35 @@ impl RevenueBondContract {
         env.storage().instance().set(&DataKey::BondOwner(bond_id), &new_owner);
     }
 
+    /// Set the beneficiary that receives a bond's redemption cash flows.
+    ///
+    /// Authorized by the current owner, decoupling the economic recipient from
+    /// whoever holds and can transfer the instrument.
+    ///
+    /// # Arguments
+    /// * `bond_id` - Bond identifier
+    /// * `owner` - Current owner (must authorize)
+    /// * `new_beneficiary` - Address to receive future redemptions
+    pub fn set_beneficiary(env: Env, bond_id: u64, owner: Address, new_beneficiary: Address) {
+        owner.require_auth();
+
+        let stored_owner: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BondOwner(bond_id))
+            .expect("bond not found");
+        assert_eq!(owner, stored_owner, "not bond owner");
+
+        env.storage()
+            .instance()
+            .set(&DataKey::BondBeneficiary(bond_id), &new_beneficiary);
+    }
+
+    /// Get the beneficiary that receives a bond's redemptions.
+    pub fn get_beneficiary(env: Env, bond_id: u64) -> Option<Address> {
+        env.storage().instance().get(&DataKey::BondBeneficiary(bond_id))
+    }
+
     /// Mark bond as defaulted (admin only).
     ///
     /// # Arguments
@@ -405,6 +799,73 @@ impl RevenueBondContract {
         assert_eq!(bond.status, BondStatus::Active, "bond not active");
         bond.status = BondStatus::Defaulted;
         env.storage().instance().set(&DataKey::Bond(bond_id), &bond);
+
+        // Default waterfall: pay the owner their remaining value out of the
+        // reserve, pro-rata when the reserve is short, and record a receipt.
+        let remaining = Self::get_remaining_value(env.clone(), bond_id);
+        let reserve: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Reserve(bond_id))
+            .unwrap_or(0);
+        let recovered = remaining.min(reserve);
+        let shortfall = remaining - recovered;
+
+        if recovered > 0 {
+            let owner: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::BondOwner(bond_id))
+                .expect("owner not found");
+            let token_client = token::Client::new(&env, &bond.token);
+            token_client.transfer(&env.current_contract_address(), &owner, &recovered);
+            env.storage()
+                .instance()
+                .set(&DataKey::Reserve(bond_id), &(reserve - recovered));
+        }
+
+        let receipt = DefaultRecovery {
+            bond_id,
+            recovered,
+            shortfall,
+        };
+        env.storage().instance().set(&DataKey::Recovery(bond_id), &receipt);
+    }
+
+    /// Top up a bond's reserve with tokens from the issuer.
+    ///
+    /// Lets the issuer post additional collateral voluntarily beyond the
+    /// fraction withheld from redemptions.
+    pub fn fund_reserve(env: Env, bond_id: u64, amount: i128) {
+        assert!(amount > 0, "amount must be positive");
+        let bond: Bond = env
+            .storage()
+            .instance()
+            .get(&DataKey::Bond(bond_id))
+            .expect("bond not found");
+        bond.issuer.require_auth();
+
+        let token_client = token::Client::new(&env, &bond.token);
+        token_client.transfer(&bond.issuer, &env.current_contract_address(), &amount);
+
+        let reserve: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Reserve(bond_id))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserve(bond_id), &(reserve + amount));
+    }
+
+    /// Get the current reserve balance for a bond.
+    pub fn get_reserve(env: Env, bond_id: u64) -> i128 {
+        env.storage().instance().get(&DataKey::Reserve(bond_id)).unwrap_or(0)
+    }
+
+    /// Get the default recovery receipt for a bond, if it has defaulted.
+    pub fn get_recovery(env: Env, bond_id: u64) -> Option<DefaultRecovery> {
+        env.storage().instance().get(&DataKey::Recovery(bond_id))
     }
 
     /// Get bond details.
@@ -422,6 +883,15 @@ impl RevenueBondContract {
         env.storage().instance().get(&DataKey::Redemption(bond_id, period))
     }
 
+    /// Get the pending (scheduled but unreleased) redemption for a period.
+    pub fn get_pending_redemption(
+        env: Env,
+        bond_id: u64,
+        period: String,
+    ) -> Option<PendingRedemption> {
+        env.storage().instance().get(&DataKey::PendingRedemption(bond_id, period))
+    }
+
     /// Get total amount redeemed for a bond.
     pub fn get_total_redeemed(env: Env, bond_id: u64) -> i128 {
         env.storage().instance().get(&DataKey::TotalRedeemed(bond_id)).unwrap_or(0)
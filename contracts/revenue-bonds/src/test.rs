@@ -1,14 +1,48 @@
 #![cfg(test)]
 use super::*;
 use soroban_sdk::{
-    testutils::Address as _,
-    token, Address, Env, String,
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
 };
+use veritasor_attestation::{AttestationContract, AttestationContractClient};
+
+/// Advance the ledger clock so a bond has fully vested under its schedule,
+/// letting redemption tests exercise payout math without the coupon curve.
+fn advance_to_maturity(env: &Env) {
+    env.ledger().set_timestamp(1_000_000_000);
+}
 
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
     token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
 }
 
+/// Register an attestation contract and authorize `admin` as its revenue
+/// oracle, so redemption tests can back their reported figures with a real
+/// on-chain attestation.
+fn setup_attestation(env: &Env, admin: &Address) -> Address {
+    let attestation_contract = env.register(AttestationContract, ());
+    let attn = AttestationContractClient::new(env, &attestation_contract);
+    attn.init(admin);
+    attn.add_authorized_analytics(admin, admin);
+    attestation_contract
+}
+
+/// Record an attestation and its attested revenue for (issuer, period) so a
+/// subsequent `redeem` clears the cross-contract revenue check.
+fn attest_revenue(
+    env: &Env,
+    attestation_contract: &Address,
+    oracle: &Address,
+    issuer: &Address,
+    period: &String,
+    revenue: &i128,
+) {
+    let attn = AttestationContractClient::new(env, attestation_contract);
+    let root = BytesN::from_array(env, &[0u8; 32]);
+    attn.submit_attestation(issuer, period, &root, &0u64, &1u32, &None, &None, &0u32, &0u32);
+    attn.set_attested_revenue(oracle, issuer, period, revenue);
+}
+
 fn setup_test() -> (Env, Address, Address, Address, Address, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
@@ -18,14 +52,14 @@ fn setup_test() -> (Env, Address, Address, Address, Address, Address, Address) {
     let issuer = Address::generate(&env);
     let owner = Address::generate(&env);
     let token_admin = Address::generate(&env);
-    
+
     let token_client = create_token_contract(&env, &token_admin);
     let token = token_client.address.clone();
 
     // Mint tokens to issuer for bond payments
     token_client.mint(&issuer, &100_000_000);
 
-    let attestation_contract = Address::generate(&env);
+    let attestation_contract = setup_attestation(&env, &admin);
 
     (env, admin, issuer, owner, token, attestation_contract, token_admin)
 }
@@ -70,6 +104,7 @@ fn test_issue_bond_fixed_structure() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
     assert_eq!(bond_id, 0);
@@ -98,6 +133,7 @@ fn test_issue_bond_revenue_linked() {
         &24,
         &attestation_contract,
         &token,
+        &0,
     );
 
     let bond = client.get_bond(&bond_id).unwrap();
@@ -124,6 +160,7 @@ fn test_issue_bond_hybrid() {
         &18,
         &attestation_contract,
         &token,
+        &0,
     );
 
     let bond = client.get_bond(&bond_id).unwrap();
@@ -149,6 +186,7 @@ fn test_issue_bond_invalid_face_value() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 }
 
@@ -171,6 +209,7 @@ fn test_issue_bond_invalid_revenue_share() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 }
 
@@ -193,6 +232,7 @@ fn test_issue_bond_invalid_payment_range() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 }
 
@@ -215,9 +255,12 @@ fn test_redeem_fixed_bond() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
     client.redeem(&bond_id, &period, &2_000_000);
 
     let redemption = client.get_redemption(&bond_id, &period).unwrap();
@@ -225,6 +268,68 @@ fn test_redeem_fixed_bond() {
     assert_eq!(client.get_total_redeemed(&bond_id), 500_000);
 }
 
+#[test]
+#[should_panic(expected = "revenue not attested")]
+fn test_redeem_rejects_unattested_revenue() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &10_000_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    advance_to_maturity(&env);
+    let period = String::from_str(&env, "2026-02");
+    // The attestation exists but the oracle only recorded 1_000 of revenue,
+    // so a larger self-reported figure must be rejected.
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000);
+    client.redeem(&bond_id, &period, &2_000_000);
+}
+
+#[test]
+#[should_panic(expected = "attestation is revoked")]
+fn test_redeem_rejects_revoked_attestation() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+    let attn = AttestationContractClient::new(&env, &attestation_contract);
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &10_000_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    advance_to_maturity(&env);
+    let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
+    attn.revoke_attestation(&admin, &issuer, &period);
+    client.redeem(&bond_id, &period, &2_000_000);
+}
+
 #[test]
 fn test_redeem_revenue_linked_bond() {
     let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
@@ -244,9 +349,12 @@ fn test_redeem_revenue_linked_bond() {
         &24,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
     client.redeem(&bond_id, &period, &5_000_000);
 
     let redemption = client.get_redemption(&bond_id, &period).unwrap();
@@ -272,9 +380,12 @@ fn test_redeem_revenue_linked_below_minimum() {
         &24,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
     client.redeem(&bond_id, &period, &500_000);
 
     let redemption = client.get_redemption(&bond_id, &period).unwrap();
@@ -300,9 +411,12 @@ fn test_redeem_revenue_linked_capped_at_max() {
         &24,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
     client.redeem(&bond_id, &period, &15_000_000);
 
     let redemption = client.get_redemption(&bond_id, &period).unwrap();
@@ -328,9 +442,12 @@ fn test_redeem_hybrid_bond() {
         &18,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
     client.redeem(&bond_id, &period, &10_000_000);
 
     let redemption = client.get_redemption(&bond_id, &period).unwrap();
@@ -357,9 +474,12 @@ fn test_redeem_double_spending_prevention() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
     client.redeem(&bond_id, &period, &2_000_000);
     client.redeem(&bond_id, &period, &2_000_000);
 }
@@ -383,14 +503,19 @@ fn test_multiple_period_redemptions() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period1 = String::from_str(&env, "2026-02");
     let period2 = String::from_str(&env, "2026-03");
     let period3 = String::from_str(&env, "2026-04");
 
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period1, &1_000_000_000);
     client.redeem(&bond_id, &period1, &2_000_000);
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period2, &1_000_000_000);
     client.redeem(&bond_id, &period2, &2_500_000);
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period3, &1_000_000_000);
     client.redeem(&bond_id, &period3, &3_000_000);
 
     assert_eq!(client.get_total_redeemed(&bond_id), 1_500_000);
@@ -416,12 +541,16 @@ fn test_full_redemption() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period1 = String::from_str(&env, "2026-02");
     let period2 = String::from_str(&env, "2026-03");
 
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period1, &1_000_000_000);
     client.redeem(&bond_id, &period1, &2_000_000);
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period2, &1_000_000_000);
     client.redeem(&bond_id, &period2, &2_000_000);
 
     let bond = client.get_bond(&bond_id).unwrap();
@@ -449,14 +578,19 @@ fn test_partial_redemption_caps_at_face_value() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period1 = String::from_str(&env, "2026-02");
     let period2 = String::from_str(&env, "2026-03");
     let period3 = String::from_str(&env, "2026-04");
 
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period1, &1_000_000_000);
     client.redeem(&bond_id, &period1, &2_000_000);
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period2, &1_000_000_000);
     client.redeem(&bond_id, &period2, &2_000_000);
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period3, &1_000_000_000);
     client.redeem(&bond_id, &period3, &2_000_000);
 
     let bond = client.get_bond(&bond_id).unwrap();
@@ -464,6 +598,166 @@ fn test_partial_redemption_caps_at_face_value() {
     assert_eq!(client.get_total_redeemed(&bond_id), 1_200_000);
 }
 
+#[test]
+fn test_beneficiary_receives_redemption() {
+    let (env, admin, issuer, owner, token, attestation_contract, token_admin) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+    let token_client = token::Client::new(&env, &token);
+    let _ = token_admin;
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &10_000_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    // Default beneficiary is the owner; redirect it to a custody account.
+    assert_eq!(client.get_beneficiary(&bond_id).unwrap(), owner);
+    let beneficiary = Address::generate(&env);
+    client.set_beneficiary(&bond_id, &owner, &beneficiary);
+
+    advance_to_maturity(&env);
+    let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
+    client.redeem(&bond_id, &period, &2_000_000);
+
+    // Cash flow lands with the beneficiary, not the owner.
+    assert_eq!(token_client.balance(&beneficiary), 500_000);
+    assert_eq!(token_client.balance(&owner), 0);
+}
+
+#[test]
+fn test_scheduled_redemption_after_and_signature() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+    let token_client = token::Client::new(&env, &token);
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &10_000_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    advance_to_maturity(&env);
+
+    // Release only after a date AND a co-signer approves.
+    let cosigner = Address::generate(&env);
+    let release_at = 500_000_000u64;
+    let mut conditions = soroban_sdk::Vec::new(&env);
+    conditions.push_back(RedemptionCondition::After(release_at));
+    conditions.push_back(RedemptionCondition::Signature(cosigner.clone()));
+
+    let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
+    client.schedule_redemption(&bond_id, &period, &500_000, &ConditionLogic::And, &conditions);
+
+    // Date satisfied but co-signer missing: still pending, no payout.
+    client.apply_witness(&bond_id, &period, &Witness::Timestamp(release_at));
+    assert!(client.get_pending_redemption(&bond_id, &period).is_some());
+    assert_eq!(token_client.balance(&owner), 0);
+
+    // Co-signer approves: plan resolves and releases exactly once.
+    client.apply_witness(&bond_id, &period, &Witness::Signature(cosigner));
+    assert!(client.get_pending_redemption(&bond_id, &period).is_none());
+    assert_eq!(token_client.balance(&owner), 500_000);
+    assert_eq!(client.get_total_redeemed(&bond_id), 500_000);
+}
+
+#[test]
+fn test_scheduled_redemption_after_uses_ledger_clock() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+    let token_client = token::Client::new(&env, &token);
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &10_000_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    // The release date is far in the future; the ledger clock sits before it.
+    let release_at = 2_000_000_000u64;
+    env.ledger().set_timestamp(1_000);
+    let mut conditions = soroban_sdk::Vec::new(&env);
+    conditions.push_back(RedemptionCondition::After(release_at));
+
+    let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
+    client.schedule_redemption(&bond_id, &period, &500_000, &ConditionLogic::And, &conditions);
+
+    // A forged witness timestamp must not satisfy the lock: the contract
+    // compares against its own ledger clock, so the claim stays pending.
+    client.apply_witness(&bond_id, &period, &Witness::Timestamp(u64::MAX));
+    assert!(client.get_pending_redemption(&bond_id, &period).is_some());
+    assert_eq!(token_client.balance(&owner), 0);
+}
+
+#[test]
+#[should_panic(expected = "attestation not found")]
+fn test_scheduled_redemption_requires_attestation() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &10_000_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    advance_to_maturity(&env);
+    let mut conditions = soroban_sdk::Vec::new(&env);
+    conditions.push_back(RedemptionCondition::After(1_000u64));
+
+    let period = String::from_str(&env, "2026-02");
+    // No attestation recorded for the period: the release must be rejected.
+    client.schedule_redemption(&bond_id, &period, &500_000, &ConditionLogic::And, &conditions);
+    client.apply_witness(&bond_id, &period, &Witness::Timestamp(0));
+}
+
 #[test]
 fn test_transfer_ownership() {
     let (env, admin, issuer, owner, _, _, _) = setup_test();
@@ -487,6 +781,7 @@ fn test_transfer_ownership() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
     client.transfer_ownership(&bond_id, &owner, &new_owner);
@@ -518,6 +813,7 @@ fn test_transfer_ownership_unauthorized() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
     client.transfer_ownership(&bond_id, &fake_owner, &new_owner);
@@ -542,6 +838,7 @@ fn test_mark_defaulted() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
     client.mark_defaulted(&admin, &bond_id);
@@ -570,12 +867,60 @@ fn test_mark_defaulted_unauthorized() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
     let non_admin = Address::generate(&env);
     client.mark_defaulted(&non_admin, &bond_id);
 }
 
+#[test]
+fn test_default_waterfall_pays_from_reserve() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+    let token_client = token::Client::new(&env, &token);
+
+    client.initialize(&admin);
+
+    // 10% of each redemption is retained as collateral.
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &10_000_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &1000,
+    );
+
+    advance_to_maturity(&env);
+    let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
+    client.redeem(&bond_id, &period, &2_000_000);
+
+    // Owner received the net payout; the reserve retained the withheld cut.
+    assert_eq!(token_client.balance(&owner), 450_000);
+    assert_eq!(client.get_reserve(&bond_id), 50_000);
+
+    // Issuer tops the reserve up voluntarily.
+    client.fund_reserve(&bond_id, &100_000);
+    assert_eq!(client.get_reserve(&bond_id), 150_000);
+
+    client.mark_defaulted(&admin, &bond_id);
+
+    // Remaining value far exceeds the reserve, so recovery is capped.
+    let recovery = client.get_recovery(&bond_id).unwrap();
+    assert_eq!(recovery.recovered, 150_000);
+    assert_eq!(recovery.shortfall, 9_500_000 - 150_000);
+    assert_eq!(token_client.balance(&owner), 450_000 + 150_000);
+    assert_eq!(client.get_reserve(&bond_id), 0);
+}
+
 #[test]
 #[should_panic(expected = "bond not active")]
 fn test_redeem_defaulted_bond() {
@@ -596,11 +941,13 @@ fn test_redeem_defaulted_bond() {
         &12,
         &attestation_contract,
         &token,
+        &0,
     );
 
     client.mark_defaulted(&admin, &bond_id);
 
     let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
     client.redeem(&bond_id, &period, &2_000_000);
 }
 
@@ -623,17 +970,110 @@ fn test_early_redemption_scenario() {
         &24,
         &attestation_contract,
         &token,
+        &0,
     );
 
+    advance_to_maturity(&env);
     let period1 = String::from_str(&env, "2026-02");
     let period2 = String::from_str(&env, "2026-03");
     let period3 = String::from_str(&env, "2026-04");
 
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period1, &1_000_000_000);
     client.redeem(&bond_id, &period1, &8_000_000);
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period2, &1_000_000_000);
     client.redeem(&bond_id, &period2, &10_000_000);
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period3, &1_000_000_000);
     client.redeem(&bond_id, &period3, &5_000_000);
 
     let bond = client.get_bond(&bond_id).unwrap();
     assert_eq!(bond.status, BondStatus::FullyRedeemed);
     assert_eq!(client.get_total_redeemed(&bond_id), 4_500_000);
 }
+
+#[test]
+#[should_panic(expected = "exceeds vested schedule")]
+fn test_redeem_exceeds_vested_schedule() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin);
+
+    // Issued at t=0 with a 12-month term; nothing has vested yet.
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &1_200_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    let period = String::from_str(&env, "2026-02");
+    attest_revenue(&env, &attestation_contract, &admin, &issuer, &period, &1_000_000_000);
+    client.redeem(&bond_id, &period, &2_000_000);
+}
+
+#[test]
+fn test_vested_amount_linear_schedule() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &1_200_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    assert_eq!(client.get_vested_amount(&bond_id), 0);
+
+    // Three months in, a quarter of the face value has vested.
+    env.ledger().set_timestamp(3 * 30 * 24 * 60 * 60);
+    assert_eq!(client.get_vested_amount(&bond_id), 300_000);
+}
+
+#[test]
+fn test_mark_matured_unlocks_full_value() {
+    let (env, admin, issuer, owner, token, attestation_contract, _) = setup_test();
+    let contract_id = env.register(RevenueBondContract, ());
+    let client = RevenueBondContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin);
+
+    let bond_id = client.issue_bond(
+        &issuer,
+        &owner,
+        &1_200_000,
+        &BondStructure::Fixed,
+        &0,
+        &500_000,
+        &500_000,
+        &12,
+        &attestation_contract,
+        &token,
+        &0,
+    );
+
+    advance_to_maturity(&env);
+    client.mark_matured(&bond_id);
+
+    let bond = client.get_bond(&bond_id).unwrap();
+    assert!(bond.matured);
+    assert_eq!(client.get_vested_amount(&bond_id), 1_200_000);
+}